@@ -6,92 +6,360 @@ use crossterm::{
 use ratatui::{
     Frame, Terminal,
     backend::CrosstermBackend,
-    layout::{Constraint, Direction, Layout},
+    layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
 };
 use regex::Regex;
-use std::{io, os::unix::process::CommandExt, process::Command, time::Duration};
+use std::{
+    io,
+    os::unix::process::CommandExt,
+    path::PathBuf,
+    process::Command,
+    time::{Duration, Instant},
+};
 
 // ---------------------------------------------------------------------------
 // State
 // ---------------------------------------------------------------------------
 
+/// A session reported by `zellij list-sessions` — either live, or
+/// dead-but-resurrectable (tagged `(EXITED - <timestamp>)` in the CLI output).
+struct Session {
+    name: String,
+    resurrectable: bool,
+    created: Option<String>,
+}
+
+/// A destructive action awaiting a `y`/`N` confirmation before it proceeds.
+enum PendingConfirm {
+    DeleteSession(String),
+    KillAllSessions(Vec<String>),
+}
+
+impl PendingConfirm {
+    fn prompt(&self) -> String {
+        match self {
+            PendingConfirm::DeleteSession(name) => {
+                format!("Kill and delete session '{}'? [y/N]", name)
+            }
+            PendingConfirm::KillAllSessions(names) => {
+                format!("Kill ALL {} sessions? [y/N]", names.len())
+            }
+        }
+    }
+}
+
+/// The layout-picking step shown after naming a new session, listing
+/// layouts discovered under the zellij config dir plus a leading "(none)".
+struct LayoutPicker {
+    session_name: String,
+    layouts: Vec<String>,
+    list_state: ListState,
+}
+
+impl LayoutPicker {
+    fn new(session_name: String, layouts: Vec<String>) -> Self {
+        let mut list_state = ListState::default();
+        list_state.select(Some(0));
+        Self {
+            session_name,
+            layouts,
+            list_state,
+        }
+    }
+
+    fn selected_layout(&self) -> Option<&str> {
+        self.list_state
+            .selected()
+            .and_then(|i| self.layouts.get(i))
+            .filter(|name| name.as_str() != "(none)")
+            .map(|s| s.as_str())
+    }
+
+    fn move_up(&mut self) {
+        let len = self.layouts.len();
+        if len == 0 {
+            return;
+        }
+        let i = self.list_state.selected().unwrap_or(0);
+        let next = if i == 0 { len - 1 } else { i - 1 };
+        self.list_state.select(Some(next));
+    }
+
+    fn move_down(&mut self) {
+        let len = self.layouts.len();
+        if len == 0 {
+            return;
+        }
+        let i = self.list_state.selected().unwrap_or(0);
+        let next = (i + 1) % len;
+        self.list_state.select(Some(next));
+    }
+}
+
 struct App {
-    sessions: Vec<String>,
+    sessions: Vec<Session>,
     list_state: ListState,
     /// If the user pressed 'n', we collect input here before exec-ing zellij.
     new_session_input: Option<String>,
+    /// If the user pressed 'r', we collect input here, pre-filled with the
+    /// current selection's name, before issuing a rename.
+    rename_input: Option<String>,
+    /// Whether the exited/resurrectable group is shown, toggled with Tab.
+    show_exited: bool,
+    /// A destructive action the user must confirm with 'y' before it fires.
+    confirm: Option<PendingConfirm>,
+    /// The layout-choice step, entered after naming a new session.
+    layout_picker: Option<LayoutPicker>,
+    /// If the user pressed '/', the fuzzy-filter query typed so far.
+    filter: Option<String>,
+    /// Digits typed for a numeric quick-jump (e.g. "1" then "2" selects #12).
+    jump_buffer: String,
+    /// When the last jump digit was typed, so a pause starts a fresh number.
+    jump_last_digit: Option<Instant>,
 }
 
 impl App {
-    fn new(sessions: Vec<String>) -> Self {
-        let mut list_state = ListState::default();
-        if !sessions.is_empty() {
-            list_state.select(Some(0));
-        }
-        Self {
+    fn new(sessions: Vec<Session>) -> Self {
+        let mut app = Self {
             sessions,
-            list_state,
+            list_state: ListState::default(),
             new_session_input: None,
-        }
+            rename_input: None,
+            show_exited: false,
+            confirm: None,
+            layout_picker: None,
+            filter: None,
+            jump_buffer: String::new(),
+            jump_last_digit: None,
+        };
+        app.reset_selection();
+        app
     }
 
     // --- helpers -----------------------------------------------------------
 
-    fn selected_session(&self) -> Option<&str> {
+    /// Sessions currently shown in the list, in display order: active first,
+    /// then exited ones if `show_exited` is on.
+    fn visible_sessions(&self) -> Vec<&Session> {
+        let mut visible: Vec<&Session> =
+            self.sessions.iter().filter(|s| !s.resurrectable).collect();
+        if self.show_exited {
+            visible.extend(self.sessions.iter().filter(|s| s.resurrectable));
+        }
+        visible
+    }
+
+    /// `visible_sessions`, further narrowed and ranked by the fuzzy filter
+    /// query when one is active.
+    fn displayed_sessions(&self) -> Vec<&Session> {
+        let visible = self.visible_sessions();
+        let Some(query) = &self.filter else {
+            return visible;
+        };
+
+        let mut scored: Vec<(i32, &Session)> = visible
+            .into_iter()
+            .filter_map(|s| fuzzy_score(query, &s.name).map(|score| (score, s)))
+            .collect();
+        scored.sort_by(|a, b| b.0.cmp(&a.0));
+        scored.into_iter().map(|(_, s)| s).collect()
+    }
+
+    fn reset_selection(&mut self) {
+        if self.displayed_sessions().is_empty() {
+            self.list_state.select(None);
+        } else {
+            self.list_state.select(Some(0));
+        }
+    }
+
+    fn toggle_exited(&mut self) {
+        self.show_exited = !self.show_exited;
+        self.reset_selection();
+    }
+
+    fn selected_session(&self) -> Option<&Session> {
         self.list_state
             .selected()
-            .and_then(|i| self.sessions.get(i))
-            .map(|s| s.as_str())
+            .and_then(|i| self.displayed_sessions().into_iter().nth(i))
     }
 
     fn move_up(&mut self) {
-        if self.sessions.is_empty() {
+        let len = self.displayed_sessions().len();
+        if len == 0 {
             return;
         }
         let i = self.list_state.selected().unwrap_or(0);
-        let next = if i == 0 {
-            self.sessions.len() - 1
-        } else {
-            i - 1
-        };
+        let next = if i == 0 { len - 1 } else { i - 1 };
         self.list_state.select(Some(next));
     }
 
     fn move_down(&mut self) {
-        if self.sessions.is_empty() {
+        let len = self.displayed_sessions().len();
+        if len == 0 {
             return;
         }
         let i = self.list_state.selected().unwrap_or(0);
-        let next = (i + 1) % self.sessions.len();
+        let next = (i + 1) % len;
         self.list_state.select(Some(next));
     }
+
+    fn jump_to_first(&mut self) {
+        if !self.displayed_sessions().is_empty() {
+            self.list_state.select(Some(0));
+        }
+    }
+
+    fn jump_to_last(&mut self) {
+        let len = self.displayed_sessions().len();
+        if len > 0 {
+            self.list_state.select(Some(len - 1));
+        }
+    }
+
+    /// Feed one typed digit into the quick-jump buffer and, if it now names a
+    /// valid 1-based index, move the selection there. A pause between digits
+    /// starts a fresh number so e.g. "1" then "2" (typed quickly) selects #12.
+    fn push_jump_digit(&mut self, digit: char) {
+        const JUMP_TIMEOUT: Duration = Duration::from_millis(600);
+
+        let now = Instant::now();
+        let fresh = self
+            .jump_last_digit
+            .map(|last| now.duration_since(last) > JUMP_TIMEOUT)
+            .unwrap_or(true);
+        if fresh {
+            self.jump_buffer.clear();
+        }
+        self.jump_buffer.push(digit);
+        self.jump_last_digit = Some(now);
+
+        let len = self.displayed_sessions().len();
+        if let Ok(n) = self.jump_buffer.parse::<usize>() {
+            if n >= 1 && n <= len {
+                self.list_state.select(Some(n - 1));
+            }
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Fuzzy filter matching
+// ---------------------------------------------------------------------------
+
+/// Subsequence-based fuzzy match: every character of `query` must appear in
+/// order within `candidate` (case-insensitive). Returns a score rewarding
+/// contiguous runs and matches right after a `-`/`_` word boundary, or
+/// `None` if `query` isn't a subsequence of `candidate`.
+fn fuzzy_score(query: &str, candidate: &str) -> Option<i32> {
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    let query: Vec<char> = query.to_lowercase().chars().collect();
+    let candidate: Vec<char> = candidate.to_lowercase().chars().collect();
+
+    let mut score = 0i32;
+    let mut qi = 0;
+    let mut last_match: Option<usize> = None;
+
+    for (ci, &ch) in candidate.iter().enumerate() {
+        if qi >= query.len() {
+            break;
+        }
+        if ch != query[qi] {
+            continue;
+        }
+
+        score += 1;
+        if last_match == Some(ci.wrapping_sub(1)) {
+            score += 5; // contiguous match
+        }
+        if ci == 0 || candidate[ci - 1] == '-' || candidate[ci - 1] == '_' {
+            score += 3; // word-boundary match
+        }
+        last_match = Some(ci);
+        qi += 1;
+    }
+
+    (qi == query.len()).then_some(score)
 }
 
 // ---------------------------------------------------------------------------
 // Fetch live sessions from zellij
 // ---------------------------------------------------------------------------
 
-fn get_sessions() -> (Vec<String>, usize) {
+/// Parse one line of `zellij list-sessions` output into a `Session`,
+/// classifying it as live or exited/resurrectable.
+fn parse_session_line(line: &str) -> Session {
+    let clean = strip_ansi_codes(line);
+    let name = parse_name(&clean);
+
+    let exited_re = Regex::new(r"\(EXITED - ([^)]+)\)").unwrap();
+    match exited_re.captures(&clean) {
+        Some(caps) => Session {
+            name,
+            resurrectable: true,
+            created: caps.get(1).map(|m| m.as_str().trim().to_string()),
+        },
+        None => Session {
+            name,
+            resurrectable: false,
+            created: None,
+        },
+    }
+}
+
+fn get_sessions() -> Vec<Session> {
     let output = Command::new("zellij").args(["list-sessions"]).output();
 
-    let sessions = match output {
+    match output {
         Ok(out) => {
             let stdout = String::from_utf8_lossy(&out.stdout);
             stdout
                 .lines()
-                .map(|l| l.trim().to_string())
+                .map(|l| l.trim())
                 .filter(|l| !l.is_empty())
+                .map(parse_session_line)
                 .collect()
         }
         Err(_) => Vec::new(),
-    };
+    }
+}
 
-    let num = &sessions.iter().len();
+// ---------------------------------------------------------------------------
+// Discover layouts available to a new session
+// ---------------------------------------------------------------------------
+
+/// Scan `~/.config/zellij/layouts` (and `$XDG_CONFIG_HOME/zellij/layouts`)
+/// for `*.kdl` layout files, returning their names prefixed by "(none)".
+fn discover_layouts() -> Vec<String> {
+    let mut dirs = Vec::new();
+    if let Some(xdg) = std::env::var_os("XDG_CONFIG_HOME") {
+        dirs.push(PathBuf::from(xdg).join("zellij/layouts"));
+    }
+    if let Some(home) = std::env::var_os("HOME") {
+        dirs.push(PathBuf::from(home).join(".config/zellij/layouts"));
+    }
 
-    (sessions, *num)
+    let mut names: Vec<String> = dirs
+        .iter()
+        .filter_map(|dir| std::fs::read_dir(dir).ok())
+        .flatten()
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().and_then(|e| e.to_str()) == Some("kdl"))
+        .filter_map(|path| path.file_stem().map(|s| s.to_string_lossy().into_owned()))
+        .collect();
+    names.sort();
+    names.dedup();
+
+    let mut layouts = vec!["(none)".to_string()];
+    layouts.extend(names);
+    layouts
 }
 
 // ---------------------------------------------------------------------------
@@ -128,13 +396,76 @@ fn ui(f: &mut Frame, app: &App) {
     );
     f.render_widget(title, chunks[0]);
 
-    // --- Session list ------------------------------------------------------
-    if app.new_session_input.is_some() {
-        // When typing a new session name we dim the list
-        let items: Vec<ListItem> = app
-            .sessions
+    // --- Session list / layout picker ---------------------------------------
+    if let Some(picker) = &app.layout_picker {
+        let items: Vec<ListItem> = picker
+            .layouts
+            .iter()
+            .map(|l| ListItem::new(format!("  {}", l)).style(Style::default().fg(Color::White)))
+            .collect();
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .title(" layout ")
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Cyan)),
+            )
+            .highlight_style(
+                Style::default()
+                    .bg(Color::Cyan)
+                    .fg(Color::Black)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol("› ");
+
+        let mut state = picker.list_state.clone();
+        f.render_stateful_widget(list, chunks[1], &mut state);
+
+        let para = Paragraph::new(Line::from(vec![
+            Span::styled("  ↑↓", Style::default().fg(Color::Cyan)),
+            Span::raw(" choose   "),
+            Span::styled("Enter", Style::default().fg(Color::Cyan)),
+            Span::raw(" select   "),
+            Span::styled("Esc", Style::default().fg(Color::Cyan)),
+            Span::raw(" skip"),
+        ]))
+        .block(
+            Block::default()
+                .borders(Borders::TOP)
+                .border_style(Style::default().fg(Color::Yellow)),
+        );
+        f.render_widget(para, chunks[2]);
+        return;
+    }
+
+    let visible = app.displayed_sessions();
+    let session_item = |i: usize, s: &Session| {
+        let index = format!("{:>3}  ", i + 1);
+        if s.resurrectable {
+            let tag = match &s.created {
+                Some(ts) => format!("(exited - {})", ts),
+                None => "(exited)".to_string(),
+            };
+            ListItem::new(format!("{}{}  {}", index, s.name, tag)).style(
+                Style::default()
+                    .fg(Color::DarkGray)
+                    .add_modifier(Modifier::ITALIC),
+            )
+        } else {
+            ListItem::new(format!("{}{}", index, s.name)).style(Style::default().fg(Color::White))
+        }
+    };
+
+    if app.new_session_input.is_some() || app.rename_input.is_some() || app.confirm.is_some() {
+        // When typing a new session name, renaming, or confirming we dim the list
+        let items: Vec<ListItem> = visible
             .iter()
-            .map(|s| ListItem::new(format!("  {}", s)).style(Style::default().fg(Color::DarkGray)))
+            .enumerate()
+            .map(|(i, s)| {
+                ListItem::new(format!("{:>3}  {}", i + 1, s.name))
+                    .style(Style::default().fg(Color::DarkGray))
+            })
             .collect();
 
         let list = List::new(items).block(
@@ -145,10 +476,10 @@ fn ui(f: &mut Frame, app: &App) {
         );
         f.render_widget(list, chunks[1]);
     } else {
-        let items: Vec<ListItem> = app
-            .sessions
+        let items: Vec<ListItem> = visible
             .iter()
-            .map(|s| ListItem::new(format!("  {}", s)).style(Style::default().fg(Color::White)))
+            .enumerate()
+            .map(|(i, s)| session_item(i, s))
             .collect();
 
         let list = List::new(items)
@@ -172,66 +503,172 @@ fn ui(f: &mut Frame, app: &App) {
     }
 
     // --- Footer ------------------------------------------------------------
-    match &app.new_session_input {
-        Some(input) => {
-            let para = Paragraph::new(Line::from(vec![
-                Span::styled(
-                    "  new session name: ",
-                    Style::default()
-                        .fg(Color::Yellow)
-                        .add_modifier(Modifier::BOLD),
-                ),
-                Span::styled(
-                    input.clone(),
-                    Style::default()
-                        .fg(Color::White)
-                        .add_modifier(Modifier::BOLD),
-                ),
-                Span::styled(
-                    "_",
-                    Style::default()
-                        .fg(Color::Yellow)
-                        .add_modifier(Modifier::SLOW_BLINK),
-                ),
-            ]))
-            .block(
-                Block::default()
-                    .borders(Borders::TOP)
-                    .border_style(Style::default().fg(Color::Yellow)),
-            );
-            f.render_widget(para, chunks[2]);
-        }
-        None => {
-            let para = Paragraph::new(Line::from(vec![
-                Span::styled("  ↑↓", Style::default().fg(Color::Cyan)),
-                Span::raw(" navigate   "),
-                Span::styled("Enter", Style::default().fg(Color::Cyan)),
-                Span::raw(" attach   "),
-                Span::styled("n", Style::default().fg(Color::Cyan)),
-                Span::raw(" new session   "),
-                Span::styled("d", Style::default().fg(Color::Cyan)),
-                Span::raw(" kill and delete session   "),
-                Span::styled("q", Style::default().fg(Color::Cyan)),
-                Span::raw(" quit"),
-            ]))
-            .block(
-                Block::default()
-                    .borders(Borders::TOP)
-                    .border_style(Style::default().fg(Color::DarkGray)),
-            );
-            f.render_widget(para, chunks[2]);
-        }
+    if let Some(input) = &app.new_session_input {
+        let para = Paragraph::new(Line::from(vec![
+            Span::styled(
+                "  new session name: ",
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                input.clone(),
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                "_",
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::SLOW_BLINK),
+            ),
+        ]))
+        .block(
+            Block::default()
+                .borders(Borders::TOP)
+                .border_style(Style::default().fg(Color::Yellow)),
+        );
+        f.render_widget(para, chunks[2]);
+    } else if let Some(input) = &app.rename_input {
+        let para = Paragraph::new(Line::from(vec![
+            Span::styled(
+                "  rename session to: ",
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                input.clone(),
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                "_",
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::SLOW_BLINK),
+            ),
+        ]))
+        .block(
+            Block::default()
+                .borders(Borders::TOP)
+                .border_style(Style::default().fg(Color::Yellow)),
+        );
+        f.render_widget(para, chunks[2]);
+    } else if let Some(query) = &app.filter {
+        let para = Paragraph::new(Line::from(vec![
+            Span::styled(
+                "  filter: ",
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                query.clone(),
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                "_",
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::SLOW_BLINK),
+            ),
+        ]))
+        .block(
+            Block::default()
+                .borders(Borders::TOP)
+                .border_style(Style::default().fg(Color::Yellow)),
+        );
+        f.render_widget(para, chunks[2]);
+    } else {
+        let para = Paragraph::new(Line::from(vec![
+            Span::styled("  ↑↓", Style::default().fg(Color::Cyan)),
+            Span::raw(" navigate   "),
+            Span::styled("Enter", Style::default().fg(Color::Cyan)),
+            Span::raw(" attach   "),
+            Span::styled("n", Style::default().fg(Color::Cyan)),
+            Span::raw(" new session   "),
+            Span::styled("r", Style::default().fg(Color::Cyan)),
+            Span::raw(" rename   "),
+            Span::styled("d", Style::default().fg(Color::Cyan)),
+            Span::raw(" kill and delete session   "),
+            Span::styled("Tab", Style::default().fg(Color::Cyan)),
+            Span::raw(" show/hide exited   "),
+            Span::styled("D", Style::default().fg(Color::Cyan)),
+            Span::raw(" kill all   "),
+            Span::styled("/", Style::default().fg(Color::Cyan)),
+            Span::raw(" filter   "),
+            Span::styled("0-9", Style::default().fg(Color::Cyan)),
+            Span::raw(" jump   "),
+            Span::styled("g/G", Style::default().fg(Color::Cyan)),
+            Span::raw(" first/last   "),
+            Span::styled("q", Style::default().fg(Color::Cyan)),
+            Span::raw(" quit"),
+        ]))
+        .block(
+            Block::default()
+                .borders(Borders::TOP)
+                .border_style(Style::default().fg(Color::DarkGray)),
+        );
+        f.render_widget(para, chunks[2]);
+    }
+
+    // --- Confirmation modal -------------------------------------------------
+    if let Some(confirm) = &app.confirm {
+        let modal_area = centered_rect(60, 20, area);
+        let modal = Paragraph::new(Line::from(vec![Span::styled(
+            confirm.prompt(),
+            Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        )]))
+        .block(
+            Block::default()
+                .title(" confirm ")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Red)),
+        );
+        f.render_widget(Clear, modal_area);
+        f.render_widget(modal, modal_area);
     }
 }
 
+/// A rect of `percent_x` × `percent_y` centered within `area`, used to float
+/// the confirmation modal over the dimmed list.
+fn centered_rect(percent_x: u16, percent_y: u16, area: Rect) -> Rect {
+    let vertical = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage((100 - percent_y) / 2),
+            Constraint::Percentage(percent_y),
+            Constraint::Percentage((100 - percent_y) / 2),
+        ])
+        .split(area);
+
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(vertical[1])[1]
+}
+
 // ---------------------------------------------------------------------------
 // What to do after the TUI exits
 // ---------------------------------------------------------------------------
 
 enum ExitAction {
     AttachSession(String),
-    NewSession(Option<String>),
+    NewSession { name: Option<String>, layout: Option<String> },
     DeleteSession(String),
+    RenameSession { old: String, new: String },
+    KillAllSessions(Vec<String>),
     Quit,
 }
 
@@ -247,12 +684,13 @@ fn run_tui() -> Result<ExitAction, Box<dyn std::error::Error>> {
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
-    let (sessions, num) = get_sessions();
+    let sessions = get_sessions();
+    let has_sessions = !sessions.is_empty();
     let mut app = App::new(sessions);
 
     let action;
 
-    if num != 0 {
+    if has_sessions {
         action = loop {
             terminal.draw(|f| ui(f, &app))?;
 
@@ -269,7 +707,9 @@ fn run_tui() -> Result<ExitAction, Box<dyn std::error::Error>> {
                                         // Empty name → cancel
                                         app.new_session_input = None;
                                     } else {
-                                        break ExitAction::NewSession(Some(name));
+                                        app.new_session_input = None;
+                                        app.layout_picker =
+                                            Some(LayoutPicker::new(name, discover_layouts()));
                                     }
                                 }
                                 KeyCode::Esc => {
@@ -289,6 +729,115 @@ fn run_tui() -> Result<ExitAction, Box<dyn std::error::Error>> {
                             continue;
                         }
 
+                        // --- Layout picker mode ---------------------------------
+                        if let Some(ref mut picker) = app.layout_picker {
+                            match key.code {
+                                KeyCode::Up | KeyCode::Char('k') => picker.move_up(),
+                                KeyCode::Down | KeyCode::Char('j') => picker.move_down(),
+                                KeyCode::Enter => {
+                                    break ExitAction::NewSession {
+                                        name: Some(picker.session_name.clone()),
+                                        layout: picker.selected_layout().map(|s| s.to_string()),
+                                    };
+                                }
+                                KeyCode::Esc => {
+                                    break ExitAction::NewSession {
+                                        name: Some(picker.session_name.clone()),
+                                        layout: None,
+                                    };
+                                }
+                                _ => {}
+                            }
+                            continue;
+                        }
+
+                        // --- Rename input mode ----------------------------------
+                        if let Some(ref mut input) = app.rename_input {
+                            match key.code {
+                                KeyCode::Enter => {
+                                    let new_name = input.trim().to_string();
+                                    let old_name = app
+                                        .selected_session()
+                                        .map(|s| s.name.clone())
+                                        .unwrap_or_default();
+                                    app.rename_input = None;
+                                    if new_name.is_empty() || old_name.is_empty() {
+                                        // Nothing to rename → cancel
+                                    } else {
+                                        break ExitAction::RenameSession {
+                                            old: old_name,
+                                            new: new_name,
+                                        };
+                                    }
+                                }
+                                KeyCode::Esc => {
+                                    app.rename_input = None;
+                                }
+                                KeyCode::Backspace => {
+                                    input.pop();
+                                }
+                                KeyCode::Char(c) => {
+                                    // Only allow valid session-name chars
+                                    if c.is_alphanumeric() || c == '-' || c == '_' {
+                                        input.push(c);
+                                    }
+                                }
+                                _ => {}
+                            }
+                            continue;
+                        }
+
+                        // --- Confirmation modal ---------------------------------
+                        if let Some(confirm) = app.confirm.take() {
+                            match key.code {
+                                KeyCode::Char('y') => match confirm {
+                                    PendingConfirm::DeleteSession(name) => {
+                                        break ExitAction::DeleteSession(name);
+                                    }
+                                    PendingConfirm::KillAllSessions(names) => {
+                                        break ExitAction::KillAllSessions(names);
+                                    }
+                                },
+                                _ => {
+                                    // Any other key cancels
+                                }
+                            }
+                            continue;
+                        }
+
+                        // --- Filter query editing --------------------------------
+                        // Esc, Backspace, and typed letters, digits, '-' and '_' are
+                        // consumed here as query edits, so letter- and digit-bound
+                        // actions (j/k, d, D/X, r, n, q, g/G, quick-jump) are not
+                        // available while a filter is active. Other keys (arrows,
+                        // Enter, Tab) fall through to the normal navigation match
+                        // below and act on the currently filtered subset via
+                        // `selected_session`.
+                        if app.filter.is_some() {
+                            match key.code {
+                                KeyCode::Esc => {
+                                    app.filter = None;
+                                    app.reset_selection();
+                                    continue;
+                                }
+                                KeyCode::Backspace => {
+                                    if let Some(query) = &mut app.filter {
+                                        query.pop();
+                                    }
+                                    app.reset_selection();
+                                    continue;
+                                }
+                                KeyCode::Char(c) if c.is_alphanumeric() || c == '-' || c == '_' => {
+                                    if let Some(query) = &mut app.filter {
+                                        query.push(c);
+                                    }
+                                    app.reset_selection();
+                                    continue;
+                                }
+                                _ => {}
+                            }
+                        }
+
                         // --- Normal navigation mode ----------------------------
                         match key.code {
                             KeyCode::Char('q') | KeyCode::Esc => {
@@ -296,19 +845,50 @@ fn run_tui() -> Result<ExitAction, Box<dyn std::error::Error>> {
                             }
                             KeyCode::Up | KeyCode::Char('k') => app.move_up(),
                             KeyCode::Down | KeyCode::Char('j') => app.move_down(),
+                            KeyCode::Char('/') => {
+                                if app.filter.is_none() {
+                                    app.filter = Some(String::new());
+                                }
+                            }
                             KeyCode::Char('d') => {
-                                if let Some(name) = app.selected_session() {
-                                    break ExitAction::DeleteSession(name.to_string());
+                                if let Some(session) = app.selected_session() {
+                                    app.confirm =
+                                        Some(PendingConfirm::DeleteSession(session.name.clone()));
+                                }
+                            }
+                            KeyCode::Char('D') | KeyCode::Char('X') => {
+                                let names: Vec<String> = app
+                                    .sessions
+                                    .iter()
+                                    .filter(|s| !s.resurrectable)
+                                    .map(|s| s.name.clone())
+                                    .collect();
+                                if !names.is_empty() {
+                                    app.confirm = Some(PendingConfirm::KillAllSessions(names));
                                 }
                             }
                             KeyCode::Enter => {
-                                if let Some(name) = app.selected_session() {
-                                    break ExitAction::AttachSession(name.to_string());
+                                if let Some(session) = app.selected_session() {
+                                    // Attaching to an exited session resurrects it.
+                                    break ExitAction::AttachSession(session.name.clone());
                                 }
                             }
                             KeyCode::Char('n') => {
                                 app.new_session_input = Some(String::new());
                             }
+                            KeyCode::Char('r') => {
+                                if let Some(session) = app.selected_session() {
+                                    app.rename_input = Some(session.name.clone());
+                                }
+                            }
+                            KeyCode::Tab => {
+                                app.toggle_exited();
+                            }
+                            KeyCode::Char('g') => app.jump_to_first(),
+                            KeyCode::Char('G') => app.jump_to_last(),
+                            KeyCode::Char(c) if c.is_ascii_digit() => {
+                                app.push_jump_digit(c);
+                            }
                             _ => {}
                         }
                     }
@@ -320,7 +900,10 @@ fn run_tui() -> Result<ExitAction, Box<dyn std::error::Error>> {
             }
         };
     } else {
-        action = ExitAction::NewSession(None);
+        action = ExitAction::NewSession {
+            name: None,
+            layout: None,
+        };
     }
 
     // Cleanup terminal
@@ -423,9 +1006,66 @@ fn main() {
                 }
             }
         }
-        ExitAction::NewSession(option) => match option {
+        ExitAction::RenameSession { old, new } => {
+            // `zellij rename-session` renames whichever session it considers
+            // "current", which it resolves from $ZELLIJ_SESSION_NAME (the
+            // same env var zellij itself sets inside an attached session) —
+            // so set that to the old session rather than attaching first.
+            let status = Command::new("zellij")
+                .env("ZELLIJ_SESSION_NAME", &old)
+                .args(["rename-session", &new])
+                .status();
+
+            match status {
+                Ok(s) if s.success() => {
+                    println!("Renamed session '{}' to '{}'", old, new);
+                }
+                Ok(s) => {
+                    eprintln!(
+                        "Failed to rename session '{}': exit code {:?}",
+                        old,
+                        s.code()
+                    );
+                    std::process::exit(1);
+                }
+                Err(e) => {
+                    eprintln!("Failed to run rename-session: {}", e);
+                    std::process::exit(1);
+                }
+            }
+        }
+        ExitAction::KillAllSessions(names) => {
+            for name in &names {
+                println!("Killing session: {}", name);
+                let status = Command::new("zellij")
+                    .args(["kill-session", name])
+                    .status();
+
+                match status {
+                    Ok(s) if s.success() => {
+                        println!("Session '{}' killed successfully", name);
+                    }
+                    Ok(s) => {
+                        eprintln!(
+                            "Failed to kill session '{}': exit code {:?}",
+                            name,
+                            s.code()
+                        );
+                    }
+                    Err(e) => {
+                        eprintln!("Failed to run kill-session for '{}': {}", name, e);
+                    }
+                }
+            }
+        }
+        ExitAction::NewSession { name, layout } => match name {
             Some(name) => {
-                let err = Command::new("zellij").args(["--session", &name]).exec();
+                let mut args = vec!["--session".to_string(), name.clone()];
+                if let Some(layout) = &layout {
+                    args.push("--layout".to_string());
+                    args.push(layout.clone());
+                }
+                let err = Command::new("zellij").args(&args).exec();
                 eprintln!("Failed to create session '{}': {}", name, err);
                 std::process::exit(1);
             }